@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+
+use crate::{Error, ErrorCode, Message, Response, Result};
+
+/// A JSON-RPC batch of messages.
+///
+/// Per the [spec](https://www.jsonrpc.org/specification#batch), a client MAY send an array of
+/// request objects instead of a single one, and a server replies with an array of response
+/// objects (one per request; [Notification](crate::Notification) entries produce no response).
+/// [Batch] serializes as a JSON array, but deserializes from either a single object or an array,
+/// so callers that do not batch are unaffected.
+#[derive(Clone, Debug)]
+pub struct Batch<T> {
+    items: Vec<T>,
+}
+
+/// A [Batch] of requests and/or notifications, sent together as a single transport frame.
+pub type RequestBatch = Batch<Message>;
+
+/// A [Batch] of responses, returned for a [RequestBatch].
+pub type ResponseBatch = Batch<Response>;
+
+impl<T> Batch<T> {
+    /// Creates a new, empty [Batch].
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Appends an item to the [Batch].
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+    /// Gets the number of items in the [Batch].
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Gets whether the [Batch] has no items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Gets an iterator over the items in the [Batch].
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    /// Validates the [Batch] per the spec: an empty batch is an
+    /// [InvalidRequest](crate::ErrorCode::InvalidRequest).
+    pub fn validate(&self) -> Result<()> {
+        if self.items.is_empty() {
+            Err(Error::new()
+                .with_code(ErrorCode::InvalidRequest)
+                .with_message("batch must not be empty"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T> Default for Batch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for Batch<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<T> IntoIterator for Batch<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Batch<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T: serde::Serialize> serde::Serialize for Batch<T> {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.items.serialize(serializer)
+    }
+}
+
+impl<'de, T: serde::de::DeserializeOwned> serde::Deserialize<'de> for Batch<T> {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+
+        let items = match value {
+            serde_json::Value::Array(values) => values
+                .into_iter()
+                .map(|v| serde_json::from_value::<T>(v).map_err(serde::de::Error::custom))
+                .collect::<core::result::Result<Vec<T>, D::Error>>()?,
+            single => {
+                let item = serde_json::from_value::<T>(single).map_err(serde::de::Error::custom)?;
+
+                alloc::vec![item]
+            }
+        };
+
+        Ok(Self { items })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Request;
+
+    #[test]
+    fn test_batch_array_round_trip() -> Result<()> {
+        let mut batch = RequestBatch::new();
+
+        batch.push(Message::Request(
+            Request::new().with_id(1).with_method("first"),
+        ));
+        batch.push(Message::Request(
+            Request::new().with_id(2).with_method("second"),
+        ));
+
+        let json = serde_json::to_string(&batch)?;
+        let parsed: RequestBatch = serde_json::from_str(&json)?;
+
+        assert_eq!(parsed.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batch_accepts_single_object() -> Result<()> {
+        let json = "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"test_method\",\"params\":null}";
+        let batch: RequestBatch = serde_json::from_str(json)?;
+
+        assert_eq!(batch.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_batch_is_invalid_request() {
+        let batch: RequestBatch = Batch::new();
+
+        assert_eq!(batch.validate().unwrap_err().code(), ErrorCode::InvalidRequest);
+    }
+
+    #[test]
+    fn test_default_does_not_require_item_default() {
+        // `Message` has no `Default` impl; this only compiles if `Batch`'s `Default` does not
+        // require one.
+        let batch = RequestBatch::default();
+
+        assert!(batch.is_empty());
+    }
+}
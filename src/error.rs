@@ -3,18 +3,50 @@
 use crate::std::{self, fmt};
 use alloc::{format, string::String};
 use serde::{ser::SerializeStruct, Serializer};
+#[cfg(not(feature = "raw-value"))]
 use serde_json::json;
 
 /// Convenience alias for the library's [Result](std::result::Result) type.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Storage for the `data` field. See the `Params` storage type of [Request](crate::Request) for
+/// the rationale behind the `raw-value` feature.
+#[cfg(not(feature = "raw-value"))]
+type Data = serde_json::Value;
+#[cfg(feature = "raw-value")]
+type Data = alloc::boxed::Box<serde_json::value::RawValue>;
+
+#[cfg(not(feature = "raw-value"))]
+fn null_data() -> Data {
+    serde_json::Value::Null
+}
+#[cfg(feature = "raw-value")]
+fn null_data() -> Data {
+    serde_json::value::RawValue::from_string(String::from("null")).expect("\"null\" is valid JSON")
+}
+
+#[cfg(not(feature = "raw-value"))]
+fn data_is_null(data: &Data) -> bool {
+    data.is_null()
+}
+#[cfg(feature = "raw-value")]
+fn data_is_null(data: &Data) -> bool {
+    data.get() == "null"
+}
+
 /// Error type for JSON-RPC specific errors.
 #[repr(C)]
-#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[derive(Clone, Debug, serde::Deserialize)]
 pub struct Error {
     code: ErrorCode,
     message: String,
-    data: serde_json::Value,
+    data: Data,
+}
+
+impl Default for Error {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl PartialEq for Error {
@@ -43,7 +75,7 @@ impl Error {
         Self {
             code: ErrorCode::new(),
             message: String::new(),
-            data: serde_json::Value::Null,
+            data: null_data(),
         }
     }
 
@@ -79,21 +111,59 @@ impl Error {
         self
     }
 
+    /// Builder function to set the [Error] code from an application-defined integer.
+    ///
+    /// Returns `Err(_)` if `code` illegally falls inside the
+    /// [reserved JSON-RPC range](ErrorCode::is_reserved) (`-32768..=-32000`), since applications
+    /// must not repurpose the codes reserved for the protocol itself.
+    pub fn with_application_code(mut self, code: i32) -> Result<Self> {
+        if ErrorCode::is_reserved(code) {
+            Err(Self::new()
+                .with_code(ErrorCode::InternalError)
+                .with_message("application error codes must not fall in the reserved JSON-RPC range"))
+        } else {
+            self.code = ErrorCode::ServerError(code);
+            Ok(self)
+        }
+    }
+
     /// Gets the [Error] data.
     ///
     /// The data is an extra field, and may be [null](serde_json::Value::Null).
+    #[cfg(not(feature = "raw-value"))]
     pub fn data(&self) -> &serde_json::Value {
         &self.data
     }
 
     /// Sets the [Error] data.
+    #[cfg(not(feature = "raw-value"))]
     pub fn set_data<T: serde::Serialize>(&mut self, data: T) {
         self.data = json!(data);
     }
 
+    /// Gets the [Error] data, as the untouched JSON text originally stored.
+    ///
+    /// The data is an extra field, and may be the literal text `"null"`.
+    #[cfg(feature = "raw-value")]
+    pub fn data(&self) -> &serde_json::value::RawValue {
+        &self.data
+    }
+
+    /// Sets the [Error] data.
+    #[cfg(feature = "raw-value")]
+    pub fn set_data<T: serde::Serialize>(&mut self, data: T) {
+        self.data = serde_json::value::to_raw_value(&data).expect("failed to serialize data");
+    }
+
+    /// Gets the untouched JSON text of the `data` field.
+    #[cfg(feature = "raw-value")]
+    pub fn raw_data(&self) -> &str {
+        self.data.get()
+    }
+
     /// Builder function to set the [Error] data.
     pub fn with_data<T: serde::Serialize>(mut self, data: T) -> Self {
-        self.data = json!(data);
+        self.set_data(data);
         self
     }
 }
@@ -103,7 +173,7 @@ impl From<serde_json::Error> for Error {
         Self {
             code: ErrorCode::ParseError,
             message: format!("{err}"),
-            data: serde_json::Value::Null,
+            data: null_data(),
         }
     }
 }
@@ -114,7 +184,7 @@ impl fmt::Display for Error {
         let message = self.message();
         let data = self.data();
 
-        if data.is_null() {
+        if data_is_null(&self.data) {
             write!(f, r#""code": {code}, "message": "{message}""#)
         } else {
             write!(
@@ -128,23 +198,28 @@ impl fmt::Display for Error {
 /// Error codes defined by the JSON-RPC 2.0 spec: <https://www.jsonrpc.org/specification#error_object>
 ///
 /// Non-exhaustive, additional types for server-specific codes may be defined in the future.
-#[repr(i32)]
 #[non_exhaustive]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum ErrorCode {
     /// A parsing error occurred.
     #[default]
-    ParseError = -32700,
+    ParseError,
     /// An invalid request was made.
-    InvalidRequest = -32600,
+    InvalidRequest,
     /// Method is not supported by the server.
-    MethodNotFound = -32601,
+    MethodNotFound,
     /// Invalid request parameters.
-    InvalidParams = -32602,
+    InvalidParams,
     /// Internal server error occurred.
-    InternalError = -32603,
+    InternalError,
+    /// An implementation-defined error, carrying its original code.
+    ///
+    /// Produced by `From<i32>` for every code outside the predefined set (including the
+    /// [server-reserved range](ErrorCode::is_server_reserved), `-32099..=-32000`), and for
+    /// application-defined codes supplied via [Error::with_application_code].
+    ServerError(i32),
     /// Unknown error occured.
-    UnknownError = -32999,
+    UnknownError,
 }
 
 impl ErrorCode {
@@ -152,11 +227,35 @@ impl ErrorCode {
     pub const fn new() -> Self {
         Self::ParseError
     }
+
+    /// The range reserved by the spec for predefined JSON-RPC error codes.
+    pub const RESERVED_RANGE: core::ops::RangeInclusive<i32> = -32768..=-32000;
+
+    /// The sub-range of [ErrorCode::RESERVED_RANGE] reserved for implementation-defined server errors.
+    pub const SERVER_ERROR_RANGE: core::ops::RangeInclusive<i32> = -32099..=-32000;
+
+    /// Gets whether `code` falls inside the [ErrorCode::RESERVED_RANGE].
+    pub fn is_reserved(code: i32) -> bool {
+        Self::RESERVED_RANGE.contains(&code)
+    }
+
+    /// Gets whether `code` falls inside the [ErrorCode::SERVER_ERROR_RANGE].
+    pub fn is_server_reserved(code: i32) -> bool {
+        Self::SERVER_ERROR_RANGE.contains(&code)
+    }
 }
 
 impl From<ErrorCode> for i32 {
     fn from(err: ErrorCode) -> Self {
-        err as i32
+        match err {
+            ErrorCode::ParseError => -32700,
+            ErrorCode::InvalidRequest => -32600,
+            ErrorCode::MethodNotFound => -32601,
+            ErrorCode::InvalidParams => -32602,
+            ErrorCode::InternalError => -32603,
+            ErrorCode::ServerError(code) => code,
+            ErrorCode::UnknownError => -32999,
+        }
     }
 }
 
@@ -174,6 +273,7 @@ impl From<ErrorCode> for &'static str {
             ErrorCode::MethodNotFound => "Method not found",
             ErrorCode::InvalidParams => "Invalid params",
             ErrorCode::InternalError => "Internal error",
+            ErrorCode::ServerError(_) => "Server error",
             ErrorCode::UnknownError => "Unknown error",
         }
     }
@@ -193,7 +293,8 @@ impl From<i32> for ErrorCode {
             v if v == -32601 => Self::MethodNotFound,
             v if v == -32602 => Self::InvalidParams,
             v if v == -32603 => Self::InternalError,
-            _ => Self::UnknownError,
+            v if v == -32999 => Self::UnknownError,
+            v => Self::ServerError(v),
         }
     }
 }
@@ -240,3 +341,32 @@ impl<'de> serde::Deserialize<'de> for ErrorCode {
         Ok(val.into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_error_round_trip() {
+        let code = ErrorCode::from(-32050);
+
+        assert_eq!(code, ErrorCode::ServerError(-32050));
+        assert_ne!(code, ErrorCode::from(-32001));
+        assert_eq!(i32::from(code), -32050);
+    }
+
+    #[test]
+    fn test_unknown_error_round_trip() {
+        assert_eq!(i32::from(ErrorCode::UnknownError), -32999);
+        assert_eq!(ErrorCode::from(-32999), ErrorCode::UnknownError);
+    }
+
+    #[test]
+    fn test_application_code_rejects_reserved_range() {
+        let err = Error::new().with_application_code(-32050);
+        assert!(err.is_err());
+
+        let err = Error::new().with_application_code(1).unwrap();
+        assert_eq!(err.code(), ErrorCode::ServerError(1));
+    }
+}
@@ -0,0 +1,179 @@
+use alloc::string::String;
+use core::fmt;
+
+/// A JSON-RPC request/response identifier.
+///
+/// Per the [spec](https://www.jsonrpc.org/specification#request_object), an `id` may be a
+/// string, a number, or `null`. Earlier versions of this crate forced every id through
+/// `id_from_value` into a `u64`, which silently corrupted string ids and could not distinguish
+/// "no id" from a numeric zero. [Id] preserves the original shape instead, and stores numbers as
+/// `i128` so the full `u64` range (a legal JSON-RPC id) round-trips losslessly.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(untagged)]
+pub enum Id {
+    /// A numeric identifier.
+    Number(i128),
+    /// A string identifier.
+    String(String),
+    /// No identifier, e.g. for a notification.
+    #[default]
+    Null,
+}
+
+impl Id {
+    /// Creates a new [Id], defaulting to [Id::Null].
+    pub const fn new() -> Self {
+        Self::Null
+    }
+
+    /// Convenience constructor for a numeric [Id].
+    pub fn number(id: u64) -> Self {
+        Self::Number(id as i128)
+    }
+
+    /// Gets whether the [Id] is [Id::Null].
+    pub fn is_null(&self) -> bool {
+        matches!(self, Self::Null)
+    }
+
+    /// Gets the [Id] as a `u64`, if it is numeric and fits in a `u64`.
+    ///
+    /// Kept as a convenience for callers migrating from the old `u64`-only ID representation.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Number(n) => u64::try_from(*n).ok(),
+            _ => None,
+        }
+    }
+
+    /// Gets the [Id] as a `&str`, if it is a string identifier.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s.as_str()),
+            _ => None,
+        }
+    }
+}
+
+// Not derived via `#[serde(untagged)]`: serde's untagged-enum deserialization buffers the input
+// through an internal `Content` type that has no `i128`/`u128` arm, so it rejects any id above
+// `i64::MAX` (see https://github.com/serde-rs/serde/issues/1331). Deserializing directly against
+// the input avoids that buffering and keeps the full `u64` range intact.
+impl<'de> serde::Deserialize<'de> for Id {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct IdVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for IdVisitor {
+            type Value = Id;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a JSON-RPC id: a string, a number, or null")
+            }
+
+            fn visit_i64<E>(self, v: i64) -> core::result::Result<Id, E> {
+                Ok(Id::Number(v as i128))
+            }
+
+            fn visit_u64<E>(self, v: u64) -> core::result::Result<Id, E> {
+                Ok(Id::Number(v as i128))
+            }
+
+            fn visit_f64<E>(self, v: f64) -> core::result::Result<Id, E> {
+                Ok(Id::Number(v as i128))
+            }
+
+            fn visit_str<E>(self, v: &str) -> core::result::Result<Id, E> {
+                Ok(Id::String(String::from(v)))
+            }
+
+            fn visit_string<E>(self, v: String) -> core::result::Result<Id, E> {
+                Ok(Id::String(v))
+            }
+
+            fn visit_unit<E>(self) -> core::result::Result<Id, E> {
+                Ok(Id::Null)
+            }
+
+            fn visit_none<E>(self) -> core::result::Result<Id, E> {
+                Ok(Id::Null)
+            }
+        }
+
+        deserializer.deserialize_any(IdVisitor)
+    }
+}
+
+impl From<u64> for Id {
+    fn from(id: u64) -> Self {
+        Self::Number(id as i128)
+    }
+}
+
+impl From<i64> for Id {
+    fn from(id: i64) -> Self {
+        Self::Number(id as i128)
+    }
+}
+
+impl From<i32> for Id {
+    fn from(id: i32) -> Self {
+        Self::Number(id as i128)
+    }
+}
+
+impl From<String> for Id {
+    fn from(id: String) -> Self {
+        Self::String(id)
+    }
+}
+
+impl From<&str> for Id {
+    fn from(id: &str) -> Self {
+        Self::String(String::from(id))
+    }
+}
+
+impl From<Option<u64>> for Id {
+    fn from(id: Option<u64>) -> Self {
+        match id {
+            Some(id) => Self::Number(id as i128),
+            None => Self::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_id_round_trip() -> crate::Result<()> {
+        let number = Id::from(1u64);
+        let string = Id::from("abc-123");
+        let null = Id::new();
+
+        assert_eq!(serde_json::to_string(&number)?, "1");
+        assert_eq!(serde_json::to_string(&string)?, "\"abc-123\"");
+        assert_eq!(serde_json::to_string(&null)?, "null");
+
+        assert_eq!(serde_json::from_str::<Id>("1")?, number);
+        assert_eq!(serde_json::from_str::<Id>("\"abc-123\"")?, string);
+        assert_eq!(serde_json::from_str::<Id>("null")?, null);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_id_number_above_i64_max_round_trips() -> crate::Result<()> {
+        let id = Id::number(u64::MAX);
+
+        assert_eq!(serde_json::to_string(&id)?, u64::MAX.to_string());
+        assert_eq!(serde_json::from_str::<Id>(&u64::MAX.to_string())?, id);
+        assert_eq!(id.as_u64(), Some(u64::MAX));
+
+        Ok(())
+    }
+}
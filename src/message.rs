@@ -0,0 +1,136 @@
+use crate::{Notification, Request, Response};
+
+/// A JSON-RPC message of unknown shape.
+///
+/// A transport only knows it has received a JSON object; it does not know in advance whether
+/// that object is a [Request], a [Response], or a [Notification]. [Message] deserializes any of
+/// the three (discriminating on the presence of `id`/`result`/`error`, since a [Request] without
+/// a response handler looks identical to a [Notification] to a plain `#[serde(untagged)]` derive)
+/// so a reader can dispatch without committing to a shape up front.
+#[derive(Clone, Debug)]
+pub enum Message {
+    /// A request expecting a [Response].
+    Request(Request),
+    /// A response to a previously sent [Request].
+    Response(Response),
+    /// A notification, for which no [Response] is expected.
+    Notification(Notification),
+}
+
+impl Message {
+    /// Gets whether the [Message] is a [Notification].
+    pub fn is_notification(&self) -> bool {
+        matches!(self, Self::Notification(_))
+    }
+
+    /// Gets whether the [Message] is a [Request].
+    pub fn is_request(&self) -> bool {
+        matches!(self, Self::Request(_))
+    }
+
+    /// Gets whether the [Message] is a [Response].
+    pub fn is_response(&self) -> bool {
+        matches!(self, Self::Response(_))
+    }
+
+    /// Converts the [Message] into a [Request], if it is one.
+    pub fn into_request(self) -> Option<Request> {
+        match self {
+            Self::Request(request) => Some(request),
+            _ => None,
+        }
+    }
+
+    /// Converts the [Message] into a [Response], if it is one.
+    pub fn into_response(self) -> Option<Response> {
+        match self {
+            Self::Response(response) => Some(response),
+            _ => None,
+        }
+    }
+
+    /// Converts the [Message] into a [Notification], if it is one.
+    pub fn into_notification(self) -> Option<Notification> {
+        match self {
+            Self::Notification(notification) => Some(notification),
+            _ => None,
+        }
+    }
+}
+
+impl serde::Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Self::Request(request) => request.serialize(serializer),
+            Self::Response(response) => response.serialize(serializer),
+            Self::Notification(notification) => notification.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Message {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let value = <serde_json::Value as serde::Deserialize>::deserialize(deserializer)?;
+
+        if value.get("result").is_some() || value.get("error").is_some() {
+            serde_json::from_value(value)
+                .map(Self::Response)
+                .map_err(serde::de::Error::custom)
+        } else if value.get("id").is_some() {
+            serde_json::from_value(value)
+                .map(Self::Request)
+                .map_err(serde::de::Error::custom)
+        } else {
+            serde_json::from_value(value)
+                .map(Self::Notification)
+                .map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_request() -> crate::Result<()> {
+        let json = "{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"test_method\",\"params\":null}";
+        let message: Message = serde_json::from_str(json)?;
+
+        assert!(message.is_request());
+        assert!(!message.is_notification());
+        assert!(!message.is_response());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_notification() -> crate::Result<()> {
+        let json = "{\"jsonrpc\":\"2.0\",\"method\":\"test_method\",\"params\":null}";
+        let message: Message = serde_json::from_str(json)?;
+
+        assert!(message.is_notification());
+        assert!(!message.is_request());
+        assert!(!message.is_response());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_response() -> crate::Result<()> {
+        let json = "{\"jsonrpc\":\"2.0\",\"id\":1,\"result\":\"ok\",\"error\":null}";
+        let message: Message = serde_json::from_str(json)?;
+
+        assert!(message.is_response());
+        assert!(!message.is_request());
+        assert!(!message.is_notification());
+
+        Ok(())
+    }
+}
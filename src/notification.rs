@@ -0,0 +1,159 @@
+use alloc::string::String;
+use serde_json::json;
+
+use crate::{Error, ErrorCode, Result};
+
+/// Storage for the `params` field. See the `Params` storage type of [Request](crate::Request) for
+/// the rationale behind the `raw-value` feature.
+#[cfg(not(feature = "raw-value"))]
+type Params = serde_json::Value;
+#[cfg(feature = "raw-value")]
+type Params = alloc::boxed::Box<serde_json::value::RawValue>;
+
+/// A JSON-RPC notification object.
+///
+/// A notification is a [Request](crate::Request) with no `id`, signalling that the sender does
+/// not expect (and the receiver must not send) a [Response](crate::Response).
+#[repr(C)]
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct Notification {
+    jsonrpc: serde_json::Value,
+    method: Option<serde_json::Value>,
+    params: Option<Params>,
+}
+
+impl Notification {
+    /// Creates a new [Notification].
+    pub fn new() -> Self {
+        Self {
+            jsonrpc: json!(String::from("2.0")),
+            method: None,
+            params: None,
+        }
+    }
+
+    /// Creates a new [Notification] with all [null](serde_json::Value::Null) fields.
+    pub fn new_null() -> Self {
+        Self {
+            jsonrpc: serde_json::Value::Null,
+            method: None,
+            params: None,
+        }
+    }
+
+    /// Gets the JSON-RPC identifier string.
+    ///
+    /// This value should always be `"2.0"`, but may also be [null](serde_json::Value::Null) or malformed.
+    pub fn jsonrpc(&self) -> Option<&str> {
+        self.jsonrpc.as_str()
+    }
+
+    /// Gets the method.
+    pub fn method(&self) -> Option<&str> {
+        if let Some(m) = self.method.as_ref() {
+            m.as_str()
+        } else {
+            None
+        }
+    }
+
+    /// Sets the method.
+    pub fn set_method(&mut self, method: &str) {
+        self.method = Some(json!(String::from(method)));
+    }
+
+    /// Builder function to set method.
+    pub fn with_method(mut self, method: &str) -> Self {
+        self.set_method(method);
+        self
+    }
+
+    /// Gets whether the params field is [null](serde_json::Value::Null).
+    pub fn params_is_null(&self) -> bool {
+        self.params.is_none()
+    }
+
+    /// Gets the [Notification] parameters.
+    ///
+    /// Attempts to parse the parameter as the provided type, returns `Err(_)` on failure.
+    #[cfg(not(feature = "raw-value"))]
+    pub fn params<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
+        if let Some(p) = self.params.as_ref() {
+            serde_json::from_value::<T>(p.clone()).map_err(|err| err.into())
+        } else {
+            Err(Error::new()
+                .with_code(ErrorCode::InvalidParams)
+                .with_message("null Params field"))
+        }
+    }
+
+    /// Sets the [Notification] parameters.
+    #[cfg(not(feature = "raw-value"))]
+    pub fn set_params<T: serde::Serialize>(&mut self, params: T) {
+        self.params = Some(json!(params));
+    }
+
+    /// Gets the [Notification] parameters, deserializing directly from the stored raw JSON text
+    /// instead of cloning an intermediate [Value](serde_json::Value).
+    ///
+    /// Attempts to parse the parameter as the provided type, returns `Err(_)` on failure.
+    #[cfg(feature = "raw-value")]
+    pub fn params<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
+        if let Some(p) = self.params.as_deref() {
+            serde_json::from_str::<T>(p.get()).map_err(|err| err.into())
+        } else {
+            Err(Error::new()
+                .with_code(ErrorCode::InvalidParams)
+                .with_message("null Params field"))
+        }
+    }
+
+    /// Sets the [Notification] parameters.
+    #[cfg(feature = "raw-value")]
+    pub fn set_params<T: serde::Serialize>(&mut self, params: T) {
+        self.params = Some(
+            serde_json::value::to_raw_value(&params).expect("failed to serialize params"),
+        );
+    }
+
+    /// Gets the untouched JSON text of the `params` field, if present.
+    #[cfg(feature = "raw-value")]
+    pub fn raw_params(&self) -> Option<&str> {
+        self.params.as_deref().map(serde_json::value::RawValue::get)
+    }
+
+    /// Builder function to set the [Notification] parameters.
+    pub fn with_params<T: serde::Serialize>(mut self, params: T) -> Self {
+        self.set_params(params);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification() -> Result<()> {
+        let exp_notification = "{\"jsonrpc\":\"2.0\",\"method\":\"test_method\",\"params\":null}";
+        let exp_params_notification =
+            "{\"jsonrpc\":\"2.0\",\"method\":\"test_params\",\"params\":[0,1,2,3]}";
+
+        let notification = Notification::new().with_method("test_method");
+
+        let params_notification = Notification::new()
+            .with_method("test_params")
+            .with_params([0, 1, 2, 3]);
+
+        assert_eq!(
+            serde_json::to_string(&notification)?.as_str(),
+            exp_notification
+        );
+        assert_eq!(
+            serde_json::to_string(&params_notification)?.as_str(),
+            exp_params_notification
+        );
+
+        Ok(())
+    }
+}
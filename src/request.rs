@@ -1,7 +1,17 @@
 use alloc::string::String;
 use serde_json::json;
 
-use crate::{id_from_value, Error, ErrorCode, Result};
+use crate::{Error, ErrorCode, Id, Result};
+
+/// Storage for the `params` field.
+///
+/// Plain [Value](serde_json::Value) by default. With the `raw-value` feature enabled, params are
+/// instead kept as unparsed JSON text (a boxed [RawValue](serde_json::value::RawValue)), so
+/// [Request::params] can deserialize straight from it without first cloning a [Value](serde_json::Value) tree.
+#[cfg(not(feature = "raw-value"))]
+type Params = serde_json::Value;
+#[cfg(feature = "raw-value")]
+type Params = alloc::boxed::Box<serde_json::value::RawValue>;
 
 /// A JSON-RPC request object
 #[repr(C)]
@@ -10,7 +20,7 @@ pub struct Request {
     jsonrpc: serde_json::Value,
     id: Option<serde_json::Value>,
     method: Option<serde_json::Value>,
-    params: Option<serde_json::Value>,
+    params: Option<Params>,
 }
 
 impl Request {
@@ -42,17 +52,22 @@ impl Request {
     }
 
     /// Gets the ID.
-    pub fn id(&self) -> Option<u64> {
-        self.id.as_ref().map(|id| id_from_value(id).unwrap_or(0))
+    pub fn id(&self) -> Id {
+        self.id
+            .as_ref()
+            .and_then(|id| serde_json::from_value::<Id>(id.clone()).ok())
+            .unwrap_or(Id::Null)
     }
 
     /// Sets the ID.
-    pub fn set_id(&mut self, id: u64) {
-        self.id = Some(json!(id));
+    pub fn set_id<T: Into<Id>>(&mut self, id: T) {
+        let id = id.into();
+
+        self.id = if id.is_null() { None } else { Some(json!(id)) };
     }
 
     /// Builder function to set ID.
-    pub fn with_id(mut self, id: u64) -> Self {
+    pub fn with_id<T: Into<Id>>(mut self, id: T) -> Self {
         self.set_id(id);
         self
     }
@@ -85,6 +100,7 @@ impl Request {
     /// Gets the [Request] parameters.
     ///
     /// Attempts to parse the parameter as the provided type, returns `Err(_)` on failure.
+    #[cfg(not(feature = "raw-value"))]
     pub fn params<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
         if let Some(p) = self.params.as_ref() {
             serde_json::from_value::<T>(p.clone()).map_err(|err| err.into())
@@ -96,10 +112,40 @@ impl Request {
     }
 
     /// Sets the [Request] parameters.
+    #[cfg(not(feature = "raw-value"))]
     pub fn set_params<T: serde::Serialize>(&mut self, params: T) {
         self.params = Some(json!(params));
     }
 
+    /// Gets the [Request] parameters, deserializing directly from the stored raw JSON text
+    /// instead of cloning an intermediate [Value](serde_json::Value).
+    ///
+    /// Attempts to parse the parameter as the provided type, returns `Err(_)` on failure.
+    #[cfg(feature = "raw-value")]
+    pub fn params<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
+        if let Some(p) = self.params.as_deref() {
+            serde_json::from_str::<T>(p.get()).map_err(|err| err.into())
+        } else {
+            Err(Error::new()
+                .with_code(ErrorCode::InvalidParams)
+                .with_message("null Params field"))
+        }
+    }
+
+    /// Sets the [Request] parameters.
+    #[cfg(feature = "raw-value")]
+    pub fn set_params<T: serde::Serialize>(&mut self, params: T) {
+        self.params = Some(
+            serde_json::value::to_raw_value(&params).expect("failed to serialize params"),
+        );
+    }
+
+    /// Gets the untouched JSON text of the `params` field, if present.
+    #[cfg(feature = "raw-value")]
+    pub fn raw_params(&self) -> Option<&str> {
+        self.params.as_deref().map(serde_json::value::RawValue::get)
+    }
+
     /// Builder function to set the [Request] parameters.
     pub fn with_params<T: serde::Serialize>(mut self, params: T) -> Self {
         self.set_params(params);
@@ -1,7 +1,14 @@
 use alloc::string::String;
 use serde_json::json;
 
-use crate::{id_from_value, Error, ErrorCode, Result};
+use crate::{Error, ErrorCode, Id, Result};
+
+/// Storage for the `result` field. See the `Params` storage type of [Request](crate::Request) for
+/// the rationale behind the `raw-value` feature.
+#[cfg(not(feature = "raw-value"))]
+type ResultValue = serde_json::Value;
+#[cfg(feature = "raw-value")]
+type ResultValue = alloc::boxed::Box<serde_json::value::RawValue>;
 
 /// A JSON-RPC response object
 #[repr(C)]
@@ -9,7 +16,7 @@ use crate::{id_from_value, Error, ErrorCode, Result};
 pub struct Response {
     jsonrpc: serde_json::Value,
     id: serde_json::Value,
-    result: Option<serde_json::Value>,
+    result: Option<ResultValue>,
     error: Option<serde_json::Value>,
 }
 
@@ -42,18 +49,18 @@ impl Response {
     }
 
     /// Gets the ID.
-    pub fn id(&self) -> Option<u64> {
-        id_from_value(&self.id)
+    pub fn id(&self) -> Id {
+        serde_json::from_value::<Id>(self.id.clone()).unwrap_or(Id::Null)
     }
 
     /// Sets the ID.
-    pub fn set_id(&mut self, id: u64) {
-        self.id = json!(id);
+    pub fn set_id<T: Into<Id>>(&mut self, id: T) {
+        self.id = json!(id.into());
     }
 
     /// Builder function to set ID.
-    pub fn with_id(mut self, id: u64) -> Self {
-        self.id = json!(id);
+    pub fn with_id<T: Into<Id>>(mut self, id: T) -> Self {
+        self.set_id(id);
         self
     }
 
@@ -65,6 +72,7 @@ impl Response {
     /// Gets the [Response] result.
     ///
     /// Attempts to parse the result as the provided type, returns `Err(_)` on failure.
+    #[cfg(not(feature = "raw-value"))]
     pub fn result<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
         if let Some(res) = self.result.as_ref() {
             serde_json::from_value::<T>(res.clone()).map_err(|err| err.into())
@@ -76,10 +84,40 @@ impl Response {
     }
 
     /// Sets the [Response] parameters.
+    #[cfg(not(feature = "raw-value"))]
     pub fn set_result<T: serde::Serialize>(&mut self, result: T) {
         self.result = Some(json!(result));
     }
 
+    /// Gets the [Response] result, deserializing directly from the stored raw JSON text instead
+    /// of cloning an intermediate [Value](serde_json::Value).
+    ///
+    /// Attempts to parse the result as the provided type, returns `Err(_)` on failure.
+    #[cfg(feature = "raw-value")]
+    pub fn result<T: for<'de> serde::Deserialize<'de>>(&self) -> Result<T> {
+        if let Some(res) = self.result.as_deref() {
+            serde_json::from_str::<T>(res.get()).map_err(|err| err.into())
+        } else {
+            Err(Error::new()
+                .with_code(ErrorCode::InvalidParams)
+                .with_message("null Result field"))
+        }
+    }
+
+    /// Sets the [Response] result.
+    #[cfg(feature = "raw-value")]
+    pub fn set_result<T: serde::Serialize>(&mut self, result: T) {
+        self.result = Some(
+            serde_json::value::to_raw_value(&result).expect("failed to serialize result"),
+        );
+    }
+
+    /// Gets the untouched JSON text of the `result` field, if present.
+    #[cfg(feature = "raw-value")]
+    pub fn raw_result(&self) -> Option<&str> {
+        self.result.as_deref().map(serde_json::value::RawValue::get)
+    }
+
     /// Builder function to set the [Response] parameters.
     pub fn with_result<T: serde::Serialize>(mut self, result: T) -> Self {
         self.set_result(result);
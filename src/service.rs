@@ -0,0 +1,160 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+
+use crate::{Error, ErrorCode, Message, Notification, Request, Response, Result};
+
+type BoxedHandler<Ctx> = Box<dyn Fn(&mut Ctx, &Request) -> Result<serde_json::Value>>;
+
+/// A transport-agnostic JSON-RPC method router.
+///
+/// [Server] takes a [Request] (or [Notification], via [Message]), routes it to the handler
+/// registered for `request.method()`, deserializes its params into the handler's expected type,
+/// and produces a [Response] — mapping an unknown method to
+/// [MethodNotFound](ErrorCode::MethodNotFound) and a params deserialization failure to
+/// [InvalidParams](ErrorCode::InvalidParams). Notifications are dispatched the same way, but
+/// their [Response] is discarded, since the spec forbids sending one.
+///
+/// `Ctx` is a user-supplied context value threaded through to every handler, e.g. a database
+/// handle or connection state.
+pub struct Server<Ctx> {
+    handlers: Vec<(String, BoxedHandler<Ctx>)>,
+}
+
+impl<Ctx> Server<Ctx> {
+    /// Creates a new, empty [Server].
+    pub fn new() -> Self {
+        Self {
+            handlers: Vec::new(),
+        }
+    }
+
+    /// Registers a handler for `method`.
+    ///
+    /// If a handler for `method` is already registered, the earlier one still wins: handlers are
+    /// tried in registration order, and the first match is used.
+    pub fn method<F, P, R>(mut self, method: &str, handler: F) -> Self
+    where
+        F: Fn(&mut Ctx, P) -> Result<R> + 'static,
+        P: for<'de> serde::Deserialize<'de>,
+        R: serde::Serialize,
+    {
+        let boxed: BoxedHandler<Ctx> = Box::new(move |ctx, request| {
+            let params: P = request.params().map_err(|_| {
+                Error::new()
+                    .with_code(ErrorCode::InvalidParams)
+                    .with_message("invalid params")
+            })?;
+
+            serde_json::to_value(handler(ctx, params)?).map_err(Error::from)
+        });
+
+        self.handlers.push((String::from(method), boxed));
+        self
+    }
+
+    /// Routes a [Request], returning the [Response] to send back.
+    pub fn handle(&self, ctx: &mut Ctx, request: &Request) -> Response {
+        let id = request.id();
+        let method = request.method().unwrap_or_default();
+
+        let response = Response::new().with_id(id);
+
+        match self.handlers.iter().find(|(name, _)| name == method) {
+            None => response.with_error(
+                Error::new()
+                    .with_code(ErrorCode::MethodNotFound)
+                    .with_message("method not found"),
+            ),
+            Some((_, handler)) => match handler(ctx, request) {
+                Ok(result) => response.with_result(result),
+                Err(err) => response.with_error(err),
+            },
+        }
+    }
+
+    /// Routes a [Notification], discarding the [Response] its handler would otherwise produce.
+    pub fn handle_notification(&self, ctx: &mut Ctx, notification: &Notification) {
+        let mut request = Request::new();
+
+        if let Some(method) = notification.method() {
+            request.set_method(method);
+        }
+
+        if let Ok(params) = notification.params::<serde_json::Value>() {
+            request.set_params(params);
+        }
+
+        self.handle(ctx, &request);
+    }
+
+    /// Routes a [Message], dispatching [Message::Request] and [Message::Notification] the same
+    /// way as [Server::handle]/[Server::handle_notification]. Returns `None` for a notification
+    /// (and for a [Message::Response], which a server never answers).
+    pub fn handle_message(&self, ctx: &mut Ctx, message: &Message) -> Option<Response> {
+        match message {
+            Message::Request(request) => Some(self.handle(ctx, request)),
+            Message::Notification(notification) => {
+                self.handle_notification(ctx, notification);
+                None
+            }
+            Message::Response(_) => None,
+        }
+    }
+}
+
+impl<Ctx> Default for Server<Ctx> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_to_registered_method() -> Result<()> {
+        let server = Server::<()>::new().method("add", |_ctx, (a, b): (i64, i64)| Ok(a + b));
+
+        let request = Request::new().with_id(1).with_method("add").with_params((1, 2));
+        let response = server.handle(&mut (), &request);
+
+        assert_eq!(response.result::<i64>()?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_method_is_method_not_found() {
+        let server = Server::<()>::new();
+        let request = Request::new().with_id(1).with_method("missing");
+
+        let response = server.handle(&mut (), &request);
+
+        assert_eq!(
+            response.error().unwrap().code(),
+            ErrorCode::MethodNotFound
+        );
+    }
+
+    #[test]
+    fn test_bad_params_is_invalid_params() {
+        let server = Server::<()>::new().method("add", |_ctx, (a, b): (i64, i64)| Ok(a + b));
+        let request = Request::new()
+            .with_id(1)
+            .with_method("add")
+            .with_params("not a tuple");
+
+        let response = server.handle(&mut (), &request);
+
+        assert_eq!(response.error().unwrap().code(), ErrorCode::InvalidParams);
+    }
+
+    #[test]
+    fn test_notification_produces_no_response() {
+        let server = Server::<()>::new().method("add", |_ctx, (a, b): (i64, i64)| Ok(a + b));
+        let notification = Notification::new().with_method("add").with_params((1, 2));
+        let message = Message::Notification(notification);
+
+        assert!(server.handle_message(&mut (), &message).is_none());
+    }
+}